@@ -0,0 +1,78 @@
+//! Minimal [DLPack](https://github.com/dmlc/dlpack) C ABI.
+//!
+//! This module only defines the subset of the DLPack structures needed to
+//! hand an `NdTensor`'s buffer to another DLPack-aware framework such as
+//! PyTorch, NumPy or CuPy without copying, or to receive one from such a
+//! framework (importing copies the data into a freshly allocated `Tensor`).
+//! `NdTensor::to_dlpack` only ever produces `Cpu` tensors (the wrapped
+//! `Tensor` always lives on the CPU), but `NdTensor::from_dlpack` must be
+//! able to recognize and reject device-resident buffers (e.g. a CuPy or
+//! PyTorch CUDA tensor) it cannot dereference as host memory, so the
+//! device codes those producers use are represented too.
+use std::os::raw::{c_int, c_void};
+
+/// The kind of device a `DLTensor`'s buffer lives on.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DLDeviceType {
+    Cpu = 1,
+    Cuda = 2,
+}
+
+/// A device on which a `DLTensor`'s buffer lives.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DLDevice {
+    pub device_type: DLDeviceType,
+    pub device_id: c_int,
+}
+
+/// The broad category of a `DLDataType`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DLDataTypeCode {
+    Int = 0,
+    UInt = 1,
+    Float = 2,
+}
+
+/// The element type of a `DLTensor`, e.g. a 32-bit signed integer.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DLDataType {
+    pub code: u8,
+    pub bits: u8,
+    pub lanes: u16,
+}
+
+/// A non-owning view of a tensor, as defined by the DLPack ABI.
+#[repr(C)]
+pub struct DLTensor {
+    pub data: *mut c_void,
+    pub device: DLDevice,
+    pub ndim: c_int,
+    pub dtype: DLDataType,
+    pub shape: *mut i64,
+    pub strides: *mut i64,
+    pub byte_offset: u64,
+}
+
+/// A `DLTensor` plus the bookkeeping needed to free its backing storage.
+///
+/// `deleter`, if present, must be called by the consumer exactly once it
+/// is done with `dl_tensor`, and never again afterwards.
+#[repr(C)]
+pub struct DLManagedTensor {
+    pub dl_tensor: DLTensor,
+    pub manager_ctx: *mut c_void,
+    pub deleter: Option<unsafe extern "C" fn(*mut DLManagedTensor)>,
+}
+
+/// Compute C-contiguous (row-major) strides, in elements, for `shape`.
+pub(crate) fn row_major_strides(shape: &[i64]) -> Vec<i64> {
+    let mut strides = vec![1i64; shape.len()];
+    for idx in (0..shape.len().saturating_sub(1)).rev() {
+        strides[idx] = strides[idx + 1] * shape[idx + 1];
+    }
+    strides
+}
@@ -24,12 +24,24 @@
 //!     arr2(&[[0, 1, 2], [3, 4, 5]]));
 //! ~~~
 
+use std::any::TypeId;
 use std::error::Error;
 use std::fmt;
+use std::ops::{Deref, DerefMut};
+use std::os::raw::c_void;
+use std::sync::Arc;
 
-use ndarray::{ArrayView, ArrayViewMut, Dimension, IntoDimension};
+use ndarray::{Array, ArrayView, ArrayViewMut, Dimension, IntoDimension, IxDyn};
 use tensorflow::{Tensor, TensorType};
 
+mod dlpack;
+#[cfg(feature = "serde")]
+mod serde_impl;
+
+pub use dlpack::{DLDataType, DLDataTypeCode, DLDevice, DLDeviceType, DLManagedTensor, DLTensor};
+
+use dlpack::row_major_strides;
+
 /// Mismatch between the tensor shape dimensionality and shape type.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ShapeError;
@@ -50,11 +62,15 @@ impl Error for ShapeError {}
 /// A Tensorflow `Tensor` only provides a limited API. This type is a
 /// wrapper around `Tensor` that makes it possible to use a tensor as
 /// an `ArrayView` or `ArrayViewMut` from the `ndarray` crate.
+///
+/// The wrapped `Tensor` is held behind an `Arc`, so operations that
+/// logically just reinterpret the same data (e.g. `to_dlpack`) can
+/// share the buffer with a cheap refcount bump instead of copying it.
 pub struct NdTensor<T, D>
 where
     T: TensorType,
 {
-    inner: Tensor<T>,
+    inner: Arc<Tensor<T>>,
     shape: D,
 }
 
@@ -80,7 +96,7 @@ where
         }
 
         Ok(NdTensor {
-            inner: tensor,
+            inner: Arc::new(tensor),
             shape,
         })
     }
@@ -99,19 +115,51 @@ where
             .collect::<Vec<_>>();
 
         NdTensor {
-            inner: Tensor::new(&shape_vec),
+            inner: Arc::new(Tensor::new(&shape_vec)),
             shape,
         }
     }
 
+    /// Construct an `NdTensor` from an owned `ndarray::Array`.
+    ///
+    /// The array is copied into a freshly allocated `Tensor`. Non-
+    /// standard-layout arrays are first materialized into standard
+    /// (C-contiguous) layout, since `Tensor` only stores data that way.
+    pub fn from_ndarray(array: Array<T, D>) -> Result<Self, ShapeError>
+    where
+        T: Clone,
+    {
+        let array = array.as_standard_layout();
+        let shape = array.raw_dim();
+
+        let shape_vec = shape
+            .as_array_view()
+            .iter()
+            .map(|&d| d as u64)
+            .collect::<Vec<_>>();
+
+        let mut tensor = Tensor::new(&shape_vec);
+        tensor.clone_from_slice(array.as_slice().ok_or(ShapeError)?);
+
+        Ok(NdTensor {
+            inner: Arc::new(tensor),
+            shape,
+        })
+    }
+
     /// Get reference to the wrapped tensor.
     pub fn inner_ref(&self) -> &Tensor<T> {
         &self.inner
     }
 
     /// Convert into the wrapped tensor.
+    ///
+    /// If this buffer is still shared with another `NdTensor` (e.g. one
+    /// obtained via `reshape`) or a live DLPack capsule, this clones the
+    /// data into a freshly owned `Tensor` rather than taking it; it is
+    /// only free when this is the last reference.
     pub fn into_inner(self) -> Tensor<T> {
-        self.inner
+        Arc::try_unwrap(self.inner).unwrap_or_else(|shared| (*shared).clone())
     }
 
     /// Get a view of the tensor.
@@ -122,10 +170,350 @@ where
     }
 
     /// Get a mutable view of the tensor.
-    pub fn view_mut(&mut self) -> ArrayViewMut<T, D> {
-        // Unwrapping is safe here, since the shape/size compatibility
-        // is guaranteed by Tensor itself.
-        ArrayViewMut::from_shape(self.shape.clone(), &mut self.inner).unwrap()
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the wrapped `Tensor`'s buffer is not
+    /// aliased by any other live `Tensor`. This crate's own `to_dlpack`
+    /// shares the buffer (via a cloned `Arc`) rather than copying it, so
+    /// a live DLPack capsule obtained from `self`, or another `NdTensor`
+    /// produced from `self` by `reshape`, aliases this buffer; calling
+    /// this while one of those is still alive is unsound. The
+    /// TensorFlow C API also does not guarantee that two
+    /// separately-obtained `Tensor`s (e.g. two outputs of the same
+    /// `Session::run`) never reference the same underlying buffer.
+    /// Prefer `ExclusiveNdTensor`, whose constructors guarantee the
+    /// buffer is not shared, when mutable access is needed.
+    pub unsafe fn view_mut(&mut self) -> ArrayViewMut<T, D> {
+        // Bypasses `Arc`'s shared-access restriction: sound only because
+        // the caller has promised exclusivity above. Unwrapping the
+        // shape is safe here, since the shape/size compatibility is
+        // guaranteed by Tensor itself.
+        let tensor = &mut *(Arc::as_ptr(&self.inner) as *mut Tensor<T>);
+        ArrayViewMut::from_shape(self.shape.clone(), tensor).unwrap()
+    }
+
+    /// Return a new-rank `NdTensor` sharing the same buffer, in a new shape.
+    ///
+    /// At most one extent of `shape` may be given as exactly `-1`, in
+    /// which case it is inferred so that the total element count stays
+    /// `self.shape.size()`; any other negative extent, more than one
+    /// `-1`, or a shape whose total does not divide evenly, is a
+    /// `ShapeError`. This is zero-copy: the result shares `self`'s
+    /// buffer via a clone of the `Arc` that backs it (a cheap refcount
+    /// bump), not a copy of the data; see `NdTensor::view_mut`'s safety
+    /// section for the aliasing this implies.
+    pub fn reshape<E>(&self, shape: &[isize]) -> Result<NdTensor<T, E>, ShapeError>
+    where
+        E: Dimension,
+    {
+        let mut new_shape = E::default();
+        if new_shape.ndim() != shape.len() {
+            return Err(ShapeError);
+        }
+
+        let total = self.shape.size();
+        let mut known_product: usize = 1;
+        let mut infer_idx = None;
+
+        for (idx, &dim) in shape.iter().enumerate() {
+            if dim == -1 {
+                if infer_idx.is_some() {
+                    return Err(ShapeError);
+                }
+                infer_idx = Some(idx);
+            } else if dim < 0 {
+                return Err(ShapeError);
+            } else {
+                known_product *= dim as usize;
+            }
+        }
+
+        let inferred = match infer_idx {
+            Some(_) => {
+                if known_product == 0 || total % known_product != 0 {
+                    return Err(ShapeError);
+                }
+                Some(total / known_product)
+            }
+            None => {
+                if known_product != total {
+                    return Err(ShapeError);
+                }
+                None
+            }
+        };
+
+        {
+            let mut shape_mut = new_shape.as_array_view_mut();
+            for (idx, &dim) in shape.iter().enumerate() {
+                shape_mut[idx] = if dim == -1 {
+                    inferred.unwrap()
+                } else {
+                    dim as usize
+                };
+            }
+        }
+
+        Ok(NdTensor {
+            inner: Arc::clone(&self.inner),
+            shape: new_shape,
+        })
+    }
+}
+
+impl<T> NdTensor<T, IxDyn>
+where
+    T: TensorType,
+{
+    /// Construct an `NdTensor` from a `Tensor` of unknown rank.
+    ///
+    /// Unlike `from_tensor`, this cannot fail on a rank mismatch: the
+    /// shape is read straight from `tensor.dims()` into an `IxDyn` of
+    /// the matching length.
+    pub fn from_tensor_dyn(tensor: Tensor<T>) -> Self {
+        let shape = IxDyn(&tensor.dims().iter().map(|&d| d as usize).collect::<Vec<_>>());
+
+        NdTensor {
+            inner: Arc::new(tensor),
+            shape,
+        }
+    }
+
+    /// Convert a dynamic-rank `NdTensor` into a statically-ranked one.
+    ///
+    /// Returns `ShapeError` if `D2`'s dimensionality does not match the
+    /// rank of this tensor, mirroring `ndarray`'s own
+    /// `ArrayBase::into_dimensionality`.
+    pub fn into_dimensionality<D2>(self) -> Result<NdTensor<T, D2>, ShapeError>
+    where
+        D2: Dimension,
+    {
+        let mut shape = D2::default();
+
+        if shape.ndim() != self.shape.ndim() {
+            return Err(ShapeError);
+        }
+
+        let src_shape = self.shape.as_array_view();
+        for idx in 0..shape.ndim() {
+            let mut shape_mut = shape.as_array_view_mut();
+            shape_mut[idx] = src_shape[idx];
+        }
+
+        Ok(NdTensor {
+            inner: self.inner,
+            shape,
+        })
+    }
+}
+
+/// Context kept alive behind a `DLManagedTensor`'s `manager_ctx`.
+///
+/// The exported `DLTensor`'s `data`/`shape`/`strides` pointers all borrow
+/// from this struct, so it must outlive the capsule. `deleter` drops it,
+/// which in turn drops this `Arc` clone of the source `NdTensor`'s
+/// buffer; the buffer itself is only freed once every `Arc` referencing
+/// it has been dropped.
+struct DLPackContext<T>
+where
+    T: TensorType,
+{
+    tensor: Arc<Tensor<T>>,
+    shape: Vec<i64>,
+    strides: Vec<i64>,
+}
+
+unsafe extern "C" fn dlpack_deleter<T>(managed: *mut DLManagedTensor)
+where
+    T: TensorType,
+{
+    if managed.is_null() {
+        return;
+    }
+
+    let managed = Box::from_raw(managed);
+    drop(Box::from_raw(managed.manager_ctx as *mut DLPackContext<T>));
+}
+
+/// Map a `TensorType` to the `DLDataType` DLPack consumers expect.
+///
+/// Returns `None` if `T` is a `TensorType` with no DLPack equivalent
+/// (e.g. `bool` or `String`).
+fn dl_data_type<T>() -> Option<DLDataType>
+where
+    T: TensorType,
+{
+    let id = TypeId::of::<T>();
+    let (code, bits) = if id == TypeId::of::<f32>() {
+        (DLDataTypeCode::Float, 32)
+    } else if id == TypeId::of::<f64>() {
+        (DLDataTypeCode::Float, 64)
+    } else if id == TypeId::of::<i8>() {
+        (DLDataTypeCode::Int, 8)
+    } else if id == TypeId::of::<i16>() {
+        (DLDataTypeCode::Int, 16)
+    } else if id == TypeId::of::<i32>() {
+        (DLDataTypeCode::Int, 32)
+    } else if id == TypeId::of::<i64>() {
+        (DLDataTypeCode::Int, 64)
+    } else if id == TypeId::of::<u8>() {
+        (DLDataTypeCode::UInt, 8)
+    } else if id == TypeId::of::<u16>() {
+        (DLDataTypeCode::UInt, 16)
+    } else if id == TypeId::of::<u32>() {
+        (DLDataTypeCode::UInt, 32)
+    } else if id == TypeId::of::<u64>() {
+        (DLDataTypeCode::UInt, 64)
+    } else {
+        return None;
+    };
+
+    Some(DLDataType {
+        code: code as u8,
+        bits,
+        lanes: 1,
+    })
+}
+
+impl<T, D> NdTensor<T, D>
+where
+    T: TensorType,
+    D: Dimension,
+{
+    /// Export this tensor as a DLPack-managed tensor.
+    ///
+    /// This shares `self`'s buffer with the exported capsule via a
+    /// clone of the `Arc` that backs it (a cheap refcount bump, not a
+    /// copy) and keeps that clone alive behind `manager_ctx` until the
+    /// consumer calls `deleter`, which it must do exactly once. The
+    /// returned pointer is otherwise a plain owning pointer: the caller
+    /// is responsible for handing it to the receiving framework.
+    ///
+    /// Because the buffer is shared, mutating `self` through
+    /// `NdTensor::view_mut` while the capsule is still alive is unsound;
+    /// see that method's safety section.
+    ///
+    /// Returns `ShapeError` if `T` has no DLPack equivalent (e.g. `bool`
+    /// or `String`).
+    pub fn to_dlpack(&self) -> Result<*mut DLManagedTensor, ShapeError> {
+        let dtype = dl_data_type::<T>().ok_or(ShapeError)?;
+
+        let shape: Vec<i64> = self.shape.as_array_view().iter().map(|&d| d as i64).collect();
+        let strides = row_major_strides(&shape);
+
+        let mut ctx = Box::new(DLPackContext {
+            tensor: Arc::clone(&self.inner),
+            shape,
+            strides,
+        });
+
+        let dl_tensor = DLTensor {
+            data: ctx.tensor.as_ptr() as *mut T as *mut c_void,
+            device: DLDevice {
+                device_type: DLDeviceType::Cpu,
+                device_id: 0,
+            },
+            ndim: ctx.shape.len() as std::os::raw::c_int,
+            dtype,
+            shape: ctx.shape.as_mut_ptr(),
+            strides: ctx.strides.as_mut_ptr(),
+            byte_offset: 0,
+        };
+
+        let managed = Box::new(DLManagedTensor {
+            dl_tensor,
+            manager_ctx: Box::into_raw(ctx) as *mut c_void,
+            deleter: Some(dlpack_deleter::<T>),
+        });
+
+        Ok(Box::into_raw(managed))
+    }
+
+    /// Import a `DLManagedTensor` exported by another framework.
+    ///
+    /// The data is copied into a freshly allocated `Tensor`; the result
+    /// does not share `managed`'s buffer.
+    ///
+    /// Returns `ShapeError` if: `managed`'s device is not the CPU (this
+    /// wrapper has no way to dereference device memory, e.g. a CUDA
+    /// buffer handed over by CuPy or a PyTorch CUDA tensor); the dtype
+    /// or rank encoded in `managed` does not match `T`/`D`; `T` has no
+    /// DLPack equivalent; or `managed`'s buffer is not row-major
+    /// contiguous (this wrapper cannot represent strided data).
+    ///
+    /// `managed`'s `deleter` is called exactly once, regardless of
+    /// whether this returns `Ok` or `Err`, so it must not be used
+    /// again afterwards either way.
+    ///
+    /// # Safety
+    ///
+    /// `managed` must point to a live `DLManagedTensor` whose `deleter`
+    /// has not yet been called, and whose `dl_tensor.data` buffer
+    /// remains valid (and is not mutated concurrently) for the duration
+    /// of this call.
+    pub unsafe fn from_dlpack(managed: *mut DLManagedTensor) -> Result<Self, ShapeError> {
+        let result = Self::import_dlpack(managed);
+
+        if let Some(deleter) = (*managed).deleter {
+            deleter(managed);
+        }
+
+        result
+    }
+
+    /// The actual import logic behind `from_dlpack`, split out so that
+    /// `from_dlpack` can free `managed` exactly once, on every path.
+    ///
+    /// # Safety
+    ///
+    /// Same preconditions as `from_dlpack`, except the caller (here,
+    /// `from_dlpack` itself) is responsible for calling `deleter`
+    /// afterwards; this function must not do so itself, since it only
+    /// borrows from `managed`.
+    unsafe fn import_dlpack(managed: *mut DLManagedTensor) -> Result<Self, ShapeError> {
+        let dl_tensor = &(*managed).dl_tensor;
+
+        if dl_tensor.device.device_type != DLDeviceType::Cpu {
+            return Err(ShapeError);
+        }
+
+        let dtype = dl_data_type::<T>().ok_or(ShapeError)?;
+        if dl_tensor.dtype != dtype {
+            return Err(ShapeError);
+        }
+
+        let mut shape = D::default();
+        if shape.ndim() != dl_tensor.ndim as usize {
+            return Err(ShapeError);
+        }
+
+        let dims = std::slice::from_raw_parts(dl_tensor.shape, dl_tensor.ndim as usize);
+
+        if !dl_tensor.strides.is_null() {
+            let strides = std::slice::from_raw_parts(dl_tensor.strides, dl_tensor.ndim as usize);
+            if strides != row_major_strides(dims) {
+                return Err(ShapeError);
+            }
+        }
+
+        for idx in 0..shape.ndim() {
+            let mut shape_mut = shape.as_array_view_mut();
+            shape_mut[idx] = dims[idx] as usize;
+        }
+
+        let len = dims.iter().product::<i64>() as usize;
+        let data_ptr = (dl_tensor.data as *const u8).add(dl_tensor.byte_offset as usize) as *const T;
+        let data = std::slice::from_raw_parts(data_ptr, len);
+
+        let dims_u64: Vec<u64> = dims.iter().map(|&d| d as u64).collect();
+        let mut tensor = Tensor::new(&dims_u64);
+        tensor.clone_from_slice(data);
+
+        Ok(NdTensor {
+            inner: Arc::new(tensor),
+            shape,
+        })
     }
 }
 
@@ -139,7 +527,102 @@ where
     }
 }
 
-impl<'a, T, D> Into<ArrayViewMut<'a, T, D>> for &'a mut NdTensor<T, D>
+/// An `NdTensor` guaranteed not to share its buffer with any other `Tensor`.
+///
+/// `NdTensor::view_mut` is `unsafe`: the TensorFlow C API does not
+/// guarantee that two independently-obtained `Tensor`s (e.g. two outputs
+/// of the same `Session::run`) never reference the same underlying
+/// buffer, so handing out a writable view is only sound if the caller
+/// knows better. `ExclusiveNdTensor` can only be built through
+/// constructors documented to allocate a fresh buffer nothing else
+/// references, so mutable access through it is sound without `unsafe`.
+pub struct ExclusiveNdTensor<T, D>
+where
+    T: TensorType,
+{
+    inner: NdTensor<T, D>,
+}
+
+impl<T, D> ExclusiveNdTensor<T, D>
+where
+    T: TensorType,
+    D: Dimension,
+{
+    /// Construct a new, exclusively-owned zero-initialized tensor.
+    ///
+    /// `NdTensor::zeros` always allocates a fresh buffer, so the result
+    /// cannot share storage with any other `Tensor`.
+    pub fn zeros<I>(shape: I) -> Self
+    where
+        I: IntoDimension<Dim = D>,
+    {
+        ExclusiveNdTensor {
+            inner: NdTensor::zeros(shape),
+        }
+    }
+
+    /// Construct an exclusively-owned tensor from an owned `ndarray::Array`.
+    ///
+    /// `NdTensor::from_ndarray` always copies into a freshly allocated
+    /// buffer, so the result cannot share storage with any other
+    /// `Tensor`.
+    pub fn from_ndarray(array: Array<T, D>) -> Result<Self, ShapeError>
+    where
+        T: Clone,
+    {
+        Ok(ExclusiveNdTensor {
+            inner: NdTensor::from_ndarray(array)?,
+        })
+    }
+
+    /// Get the tensor's data as a contiguous, immutable slice.
+    pub fn as_slice(&self) -> &[T] {
+        &self.inner.inner
+    }
+
+    /// Get the tensor's data as a contiguous, mutable slice.
+    ///
+    /// Sound because `ExclusiveNdTensor` is only ever built over a
+    /// buffer known not to be shared. Panics if a `reshape` or
+    /// `to_dlpack` call made through the `Deref` to `NdTensor` has since
+    /// shared the buffer after all.
+    pub fn as_slice_mut(&mut self) -> &mut [T] {
+        Arc::get_mut(&mut self.inner.inner)
+            .expect("ExclusiveNdTensor's buffer is no longer exclusively owned")
+    }
+
+    /// Get a raw pointer to the tensor's data.
+    pub fn data_ptr(&self) -> *const T {
+        self.inner.inner.as_ptr()
+    }
+
+    /// Get a mutable view of the tensor.
+    ///
+    /// Unlike `NdTensor::view_mut`, this is safe: `ExclusiveNdTensor`
+    /// guarantees its buffer is not shared with any other `Tensor`.
+    /// Panics if a `reshape` or `to_dlpack` call made through the
+    /// `Deref` to `NdTensor` has since shared the buffer after all.
+    pub fn view_mut(&mut self) -> ArrayViewMut<T, D> {
+        assert!(
+            Arc::get_mut(&mut self.inner.inner).is_some(),
+            "ExclusiveNdTensor's buffer is no longer exclusively owned"
+        );
+        // Safe: checked above that this is still the only reference to
+        // the buffer.
+        unsafe { self.inner.view_mut() }
+    }
+
+    /// Convert back into a plain `NdTensor`.
+    ///
+    /// The result is just as valid as any other `NdTensor`, but no
+    /// longer carries the exclusivity guarantee, so its `view_mut` is
+    /// `unsafe` again.
+    pub fn into_shared(self) -> NdTensor<T, D> {
+        self.inner
+    }
+}
+
+impl<'a, T, D> Into<ArrayViewMut<'a, T, D>> for &'a mut ExclusiveNdTensor<T, D>
 where
     T: TensorType,
     D: Dimension,
@@ -149,12 +632,35 @@ where
     }
 }
 
+impl<T, D> Deref for ExclusiveNdTensor<T, D>
+where
+    T: TensorType,
+{
+    type Target = NdTensor<T, D>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T, D> DerefMut for ExclusiveNdTensor<T, D>
+where
+    T: TensorType,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use ndarray::{arr1, arr2, Ix1, Ix2};
+    use ndarray::{arr1, arr2, IxDyn, Ix1, Ix2};
     use tensorflow::Tensor;
 
-    use super::NdTensor;
+    use super::{
+        DLDataType, DLDataTypeCode, DLDevice, DLDeviceType, DLManagedTensor, DLTensor,
+        ExclusiveNdTensor, NdTensor,
+    };
 
     #[test]
     fn view() {
@@ -171,7 +677,9 @@ mod tests {
             .with_values(&[0u32, 1, 2, 3, 4, 5])
             .unwrap();
         let mut array = NdTensor::from_tensor(tensor).unwrap();
-        array.view_mut()[(0, 2)] = 42;
+        unsafe {
+            array.view_mut()[(0, 2)] = 42;
+        }
 
         assert_eq!(array.view(), arr2(&[[0, 1, 42], [3, 4, 5]]));
     }
@@ -188,6 +696,237 @@ mod tests {
     #[test]
     fn zeros() {
         let mut array: NdTensor<i32, Ix2> = NdTensor::zeros([2usize, 3]);
-        array.view_mut().row_mut(0).assign(&arr1(&[1i32, 2, 3]));
+        unsafe { array.view_mut() }.row_mut(0).assign(&arr1(&[1i32, 2, 3]));
+    }
+
+    #[test]
+    fn reshape_flatten() {
+        let tensor = Tensor::new(&[2, 3])
+            .with_values(&[0u32, 1, 2, 3, 4, 5])
+            .unwrap();
+        let array: NdTensor<u32, Ix2> = NdTensor::from_tensor(tensor).unwrap();
+
+        let flat: NdTensor<u32, Ix1> = array.reshape(&[6]).unwrap();
+        assert_eq!(flat.view(), arr1(&[0, 1, 2, 3, 4, 5]));
+    }
+
+    #[test]
+    fn reshape_inferred_dimension() {
+        let tensor = Tensor::new(&[2, 3])
+            .with_values(&[0u32, 1, 2, 3, 4, 5])
+            .unwrap();
+        let array: NdTensor<u32, Ix2> = NdTensor::from_tensor(tensor).unwrap();
+
+        let reshaped: NdTensor<u32, Ix2> = array.reshape(&[-1, 3]).unwrap();
+        assert_eq!(reshaped.view(), arr2(&[[0, 1, 2], [3, 4, 5]]));
+    }
+
+    #[test]
+    fn reshape_indivisible() {
+        let tensor = Tensor::new(&[2, 3])
+            .with_values(&[0u32, 1, 2, 3, 4, 5])
+            .unwrap();
+        let array: NdTensor<u32, Ix2> = NdTensor::from_tensor(tensor).unwrap();
+
+        let reshaped = array.reshape::<Ix2>(&[-1, 4]);
+        assert!(reshaped.is_err());
+    }
+
+    #[test]
+    fn reshape_rejects_non_neg_one_sentinel() {
+        let tensor = Tensor::new(&[2, 3])
+            .with_values(&[0u32, 1, 2, 3, 4, 5])
+            .unwrap();
+        let array: NdTensor<u32, Ix2> = NdTensor::from_tensor(tensor).unwrap();
+
+        let reshaped = array.reshape::<Ix2>(&[-2, 3]);
+        assert!(reshaped.is_err());
+    }
+
+    #[test]
+    fn from_ndarray() {
+        let data = arr2(&[[0u32, 1, 2], [3, 4, 5]]);
+        let array: NdTensor<u32, Ix2> = NdTensor::from_ndarray(data).unwrap();
+        assert_eq!(array.view(), arr2(&[[0, 1, 2], [3, 4, 5]]));
+    }
+
+    #[test]
+    fn from_tensor_dyn() {
+        let tensor = Tensor::new(&[2, 3])
+            .with_values(&[0u32, 1, 2, 3, 4, 5])
+            .unwrap();
+        let array: NdTensor<u32, IxDyn> = NdTensor::from_tensor_dyn(tensor);
+        assert_eq!(array.view(), arr2(&[[0, 1, 2], [3, 4, 5]]).into_dyn());
+    }
+
+    #[test]
+    fn into_dimensionality() {
+        let tensor = Tensor::new(&[2, 3])
+            .with_values(&[0u32, 1, 2, 3, 4, 5])
+            .unwrap();
+        let array: NdTensor<u32, IxDyn> = NdTensor::from_tensor_dyn(tensor);
+        let array: NdTensor<u32, Ix2> = array.into_dimensionality().unwrap();
+        assert_eq!(array.view(), arr2(&[[0, 1, 2], [3, 4, 5]]));
+    }
+
+    #[test]
+    fn into_dimensionality_rank_mismatch() {
+        let tensor = Tensor::new(&[2, 3])
+            .with_values(&[0u32, 1, 2, 3, 4, 5])
+            .unwrap();
+        let array: NdTensor<u32, IxDyn> = NdTensor::from_tensor_dyn(tensor);
+        let array = array.into_dimensionality::<Ix1>();
+        assert!(array.is_err());
+    }
+
+    #[test]
+    fn exclusive_zeros() {
+        let mut array: ExclusiveNdTensor<i32, Ix2> = ExclusiveNdTensor::zeros([2usize, 3]);
+        array.as_slice_mut()[2] = 42;
+        assert_eq!(array.as_slice(), &[0, 0, 42, 0, 0, 0]);
+        assert_eq!(array.view(), arr2(&[[0, 0, 42], [0, 0, 0]]));
+    }
+
+    #[test]
+    fn exclusive_view_mut() {
+        let mut array: ExclusiveNdTensor<i32, Ix2> = ExclusiveNdTensor::zeros([2usize, 3]);
+        array.view_mut()[(0, 2)] = 42;
+        assert_eq!(array.view(), arr2(&[[0, 0, 42], [0, 0, 0]]));
+    }
+
+    #[test]
+    fn exclusive_from_ndarray() {
+        let data = arr2(&[[0u32, 1, 2], [3, 4, 5]]);
+        let array: ExclusiveNdTensor<u32, Ix2> = ExclusiveNdTensor::from_ndarray(data).unwrap();
+        assert_eq!(array.as_slice(), &[0, 1, 2, 3, 4, 5]);
+
+        let shared = array.into_shared();
+        assert_eq!(shared.view(), arr2(&[[0, 1, 2], [3, 4, 5]]));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_roundtrip() {
+        let tensor = Tensor::new(&[2, 3])
+            .with_values(&[0u32, 1, 2, 3, 4, 5])
+            .unwrap();
+        let array: NdTensor<u32, Ix2> = NdTensor::from_tensor(tensor).unwrap();
+
+        let json = serde_json::to_string(&array).unwrap();
+        let deserialized: NdTensor<u32, Ix2> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(deserialized.view(), arr2(&[[0, 1, 2], [3, 4, 5]]));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_rank_mismatch() {
+        let tensor = Tensor::new(&[2, 3])
+            .with_values(&[0u32, 1, 2, 3, 4, 5])
+            .unwrap();
+        let array: NdTensor<u32, Ix2> = NdTensor::from_tensor(tensor).unwrap();
+
+        let json = serde_json::to_string(&array).unwrap();
+        let deserialized = serde_json::from_str::<NdTensor<u32, Ix1>>(&json);
+
+        assert!(deserialized.is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_data_length_mismatch() {
+        let json = r#"{"shape":[2,3],"data":[0,1,2,3,4]}"#;
+        let deserialized = serde_json::from_str::<NdTensor<u32, Ix2>>(json);
+
+        assert!(deserialized.is_err());
+    }
+
+    #[test]
+    fn dlpack_roundtrip() {
+        let tensor = Tensor::new(&[2, 3])
+            .with_values(&[0u32, 1, 2, 3, 4, 5])
+            .unwrap();
+        let array: NdTensor<u32, Ix2> = NdTensor::from_tensor(tensor).unwrap();
+
+        let managed = array.to_dlpack().unwrap();
+        let imported: NdTensor<u32, Ix2> = unsafe { NdTensor::from_dlpack(managed).unwrap() };
+
+        assert_eq!(imported.view(), arr2(&[[0, 1, 2], [3, 4, 5]]));
+    }
+
+    #[test]
+    fn dlpack_rank_mismatch() {
+        let tensor = Tensor::new(&[2, 3])
+            .with_values(&[0u32, 1, 2, 3, 4, 5])
+            .unwrap();
+        let array: NdTensor<u32, Ix2> = NdTensor::from_tensor(tensor).unwrap();
+
+        let managed = array.to_dlpack().unwrap();
+        let imported = unsafe { NdTensor::<u32, Ix1>::from_dlpack(managed) };
+
+        assert!(imported.is_err());
+    }
+
+    #[test]
+    fn dlpack_rejects_non_cpu_device() {
+        let mut data = [0u32, 1, 2, 3, 4, 5];
+        let mut shape = [2i64, 3];
+        let mut strides = [3i64, 1];
+
+        let mut managed = DLManagedTensor {
+            dl_tensor: DLTensor {
+                data: data.as_mut_ptr() as *mut std::os::raw::c_void,
+                device: DLDevice {
+                    device_type: DLDeviceType::Cuda,
+                    device_id: 0,
+                },
+                ndim: 2,
+                dtype: DLDataType {
+                    code: DLDataTypeCode::UInt as u8,
+                    bits: 32,
+                    lanes: 1,
+                },
+                shape: shape.as_mut_ptr(),
+                strides: strides.as_mut_ptr(),
+                byte_offset: 0,
+            },
+            manager_ctx: std::ptr::null_mut(),
+            deleter: None,
+        };
+
+        let imported = unsafe { NdTensor::<u32, Ix2>::from_dlpack(&mut managed) };
+        assert!(imported.is_err());
+    }
+
+    #[test]
+    fn dlpack_respects_byte_offset() {
+        let data = [0xffu32, 0, 1, 2, 3, 4, 5];
+        let mut shape = [2i64, 3];
+        let mut strides = [3i64, 1];
+
+        let mut managed = DLManagedTensor {
+            dl_tensor: DLTensor {
+                data: data.as_ptr() as *mut std::os::raw::c_void,
+                device: DLDevice {
+                    device_type: DLDeviceType::Cpu,
+                    device_id: 0,
+                },
+                ndim: 2,
+                dtype: DLDataType {
+                    code: DLDataTypeCode::UInt as u8,
+                    bits: 32,
+                    lanes: 1,
+                },
+                shape: shape.as_mut_ptr(),
+                strides: strides.as_mut_ptr(),
+                byte_offset: std::mem::size_of::<u32>() as u64,
+            },
+            manager_ctx: std::ptr::null_mut(),
+            deleter: None,
+        };
+
+        let imported: NdTensor<u32, Ix2> =
+            unsafe { NdTensor::from_dlpack(&mut managed).unwrap() };
+        assert_eq!(imported.view(), arr2(&[[0, 1, 2], [3, 4, 5]]));
     }
 }
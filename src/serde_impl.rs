@@ -0,0 +1,85 @@
+//! `serde` support for `NdTensor`, enabled by the `serde` feature.
+//!
+//! A tensor is serialized as its shape (a `Vec<usize>`) plus the
+//! contiguous element data; deserializing rebuilds a fresh `Tensor` and
+//! fails if the recorded rank does not match the static `D`.
+
+use std::sync::Arc;
+
+use serde::de::{self, Deserializer};
+use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+
+use ndarray::Dimension;
+use tensorflow::{Tensor, TensorType};
+
+use crate::NdTensor;
+
+impl<T, D> Serialize for NdTensor<T, D>
+where
+    T: TensorType + Serialize,
+    D: Dimension,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let shape: Vec<usize> = self.shape.as_array_view().iter().cloned().collect();
+
+        let mut state = serializer.serialize_struct("NdTensor", 2)?;
+        state.serialize_field("shape", &shape)?;
+        state.serialize_field("data", &self.inner[..])?;
+        state.end()
+    }
+}
+
+/// On-the-wire representation of an `NdTensor`, rank-agnostic.
+#[derive(Deserialize)]
+#[serde(bound(deserialize = "T: Deserialize<'de>"))]
+struct NdTensorData<T> {
+    shape: Vec<usize>,
+    data: Vec<T>,
+}
+
+impl<'de, T, D> Deserialize<'de> for NdTensor<T, D>
+where
+    T: TensorType + Deserialize<'de>,
+    D: Dimension,
+{
+    fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+    where
+        De: Deserializer<'de>,
+    {
+        let raw = NdTensorData::<T>::deserialize(deserializer)?;
+
+        let mut shape = D::default();
+        if shape.ndim() != raw.shape.len() {
+            return Err(de::Error::custom(
+                "rank of the serialized tensor does not match the shape type",
+            ));
+        }
+
+        {
+            let mut shape_mut = shape.as_array_view_mut();
+            for (idx, &dim) in raw.shape.iter().enumerate() {
+                shape_mut[idx] = dim;
+            }
+        }
+
+        let expected_len: usize = raw.shape.iter().product();
+        if raw.data.len() != expected_len {
+            return Err(de::Error::custom(
+                "number of serialized elements does not match the serialized shape",
+            ));
+        }
+
+        let shape_vec: Vec<u64> = raw.shape.iter().map(|&d| d as u64).collect();
+        let mut tensor = Tensor::new(&shape_vec);
+        tensor.clone_from_slice(&raw.data);
+
+        Ok(NdTensor {
+            inner: Arc::new(tensor),
+            shape,
+        })
+    }
+}